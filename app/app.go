@@ -177,6 +177,19 @@ func (a *App) Stop() error {
 	return nil
 }
 
+// Ping measures the round-trip time to the NATS server. It is a cheap liveness
+// check, and can also be used to wait for NATS to become reachable on startup.
+func (a *App) Ping() (time.Duration, error) {
+	if a.nc == nil {
+		return 0, nats.ErrConnectionClosed
+	}
+	rtt, err := a.nc.RTT()
+	if err != nil {
+		return 0, fmt.Errorf("error pinging NATS server: %w", err)
+	}
+	return rtt, nil
+}
+
 func (a *App) startNats() error {
 	// Setup and connect to NATS
 	if a.config.NATS.Embedded {
@@ -1,8 +1,11 @@
 package app
 
 import (
+	"errors"
 	"testing"
 	"time"
+
+	"github.com/nats-io/nats.go"
 )
 
 func TestStartEmbeddedNatsServer(t *testing.T) {
@@ -61,3 +64,35 @@ func TestNATSConnectionFailure(t *testing.T) {
 		t.Error("Expected error when connecting to non-existent NATS server")
 	}
 }
+
+func TestPing(t *testing.T) {
+	app := New(Config{
+		Name: "test-app",
+		NATS: NATSConfig{
+			Embedded: true,
+			Private:  true,
+		},
+	})
+
+	if _, err := app.Ping(); err == nil {
+		t.Error("Expected error when pinging before NATS is started")
+	}
+
+	if err := app.startNats(); err != nil {
+		t.Fatalf("Failed to start NATS: %v", err)
+	}
+
+	rtt, err := app.Ping()
+	if err != nil {
+		t.Fatalf("Failed to ping NATS: %v", err)
+	}
+	if rtt <= 0 {
+		t.Errorf("Expected positive round-trip time, got %v", rtt)
+	}
+
+	app.stopNats()
+
+	if _, err := app.Ping(); !errors.Is(err, nats.ErrConnectionClosed) {
+		t.Errorf("Expected ErrConnectionClosed after NATS is stopped, got %v", err)
+	}
+}